@@ -0,0 +1,200 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use polars_core::prelude::*;
+use polars_error::{polars_err, PolarsResult};
+use serde_json::Value;
+
+use super::infer;
+use super::tape::{Tape, Token, TokenKind};
+
+/// Parse `lines` in batches of `batch_size` through a reusable [`Tape`], materializing
+/// each batch's `schema` columns directly from tape tokens and `vstack`-ing the
+/// resulting per-batch frames. Scalar fields are built straight from token spans
+/// without ever constructing a [`Value`]; a nested (`List`/`Struct`) field falls back
+/// to reconstructing a `Value` for just that field's sub-tree, reusing the same
+/// materialization code path as the non-batched reader.
+pub(crate) fn read_lines_batched(
+    lines: &[&str],
+    schema: &Schema,
+    batch_size: usize,
+) -> PolarsResult<DataFrame> {
+    let batch_size = batch_size.max(1);
+    let mut tape = Tape::default();
+    let mut batches = Vec::with_capacity(lines.len().div_ceil(batch_size));
+
+    for chunk in lines.chunks(batch_size) {
+        tape.clear();
+        let roots: Vec<(usize, &[u8])> = chunk
+            .iter()
+            .map(|line| {
+                let src = line.as_bytes();
+                tape.parse(src).map(|root| (root, src))
+            })
+            .collect::<PolarsResult<_>>()?;
+
+        // Index each record's keys once up front, so looking a field up is an O(1)
+        // amortized map lookup per (field, record) instead of rescanning every key in
+        // the record for every field.
+        let indices: Vec<HashMap<Cow<str>, usize>> = roots
+            .iter()
+            .map(|(root, src)| tape.object_index(src, *root))
+            .collect();
+
+        let columns = schema
+            .iter_fields()
+            .map(|field| build_column(&tape, &roots, &indices, &field))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        batches.push(DataFrame::new(columns)?);
+    }
+
+    let mut frames = batches.into_iter();
+    let Some(mut df) = frames.next() else {
+        return DataFrame::new(
+            schema
+                .iter_fields()
+                .map(|f| Series::new_empty(f.name(), f.dtype()))
+                .collect(),
+        );
+    };
+    for batch in frames {
+        df.vstack_mut(&batch)?;
+    }
+    Ok(df)
+}
+
+fn build_column(
+    tape: &Tape,
+    roots: &[(usize, &[u8])],
+    indices: &[HashMap<Cow<str>, usize>],
+    field: &Field,
+) -> PolarsResult<Series> {
+    match field.dtype() {
+        DataType::List(_) | DataType::Struct(_) => {
+            // Nested fields are rare relative to scalar columns in the typical wide
+            // record this path targets; reconstruct just their sub-tree as a `Value`
+            // and hand off to the existing recursive materializer.
+            let values: Vec<Value> = roots
+                .iter()
+                .zip(indices)
+                .map(|((_, src), index)| {
+                    index
+                        .get(field.name().as_str())
+                        .map(|&idx| value_from_tape(tape, src, idx))
+                        .unwrap_or(Value::Null)
+                })
+                .collect();
+            let as_refs: Vec<Option<&Value>> = values.iter().map(Some).collect();
+            infer::series_from_dtype(field.name(), field.dtype(), &as_refs)
+        },
+        dtype => build_scalar_column(tape, roots, indices, field.name(), dtype),
+    }
+}
+
+fn build_scalar_column(
+    tape: &Tape,
+    roots: &[(usize, &[u8])],
+    indices: &[HashMap<Cow<str>, usize>],
+    name: &str,
+    dtype: &DataType,
+) -> PolarsResult<Series> {
+    let values: Vec<Option<Token>> = indices
+        .iter()
+        .map(|index| {
+            index
+                .get(name)
+                .map(|&idx| tape.token(idx))
+                .filter(|tok| tok.kind != TokenKind::Null)
+        })
+        .collect();
+
+    let series = match dtype {
+        DataType::Boolean => {
+            let ca: BooleanChunked = roots
+                .iter()
+                .zip(&values)
+                .map(|(_, tok)| tok.map(|t| tape.bool_value(t)))
+                .collect();
+            ca.with_name(name).into_series()
+        },
+        DataType::Int64 => {
+            let ca: Int64Chunked = roots
+                .iter()
+                .zip(&values)
+                .map(|((_, src), tok)| tok.and_then(|t| tape.number_text(src, t).parse().ok()))
+                .collect();
+            ca.with_name(name).into_series()
+        },
+        DataType::UInt64 => {
+            let ca: UInt64Chunked = roots
+                .iter()
+                .zip(&values)
+                .map(|((_, src), tok)| tok.and_then(|t| tape.number_text(src, t).parse().ok()))
+                .collect();
+            ca.with_name(name).into_series()
+        },
+        DataType::Float64 => {
+            let ca: Float64Chunked = roots
+                .iter()
+                .zip(&values)
+                .map(|((_, src), tok)| tok.and_then(|t| tape.number_text(src, t).parse().ok()))
+                .collect();
+            ca.with_name(name).into_series()
+        },
+        DataType::Utf8 => {
+            let ca: Utf8Chunked = roots
+                .iter()
+                .zip(&values)
+                .map(|((_, src), tok)| {
+                    tok.map(|t| match t.kind {
+                        TokenKind::String => tape.string_value(src, t).into_owned(),
+                        _ => tape.number_text(src, t).to_string(),
+                    })
+                })
+                .collect();
+            ca.with_name(name).into_series()
+        },
+        // A field seen only as `null` within the inference window has no other
+        // dtype to widen to; build an all-null series rather than panicking.
+        DataType::Null => Series::full_null(name, roots.len(), &DataType::Null),
+        other => {
+            return Err(
+                polars_err!(ComputeError: "unsupported dtype {other:?} for JSON field {name:?}"),
+            )
+        },
+    };
+    Ok(series)
+}
+
+fn value_from_tape(tape: &Tape, src: &[u8], idx: usize) -> Value {
+    let tok = tape.token(idx);
+    match tok.kind {
+        TokenKind::Null => Value::Null,
+        TokenKind::True => Value::Bool(true),
+        TokenKind::False => Value::Bool(false),
+        TokenKind::Number => {
+            let text = tape.number_text(src, tok);
+            text.parse::<i64>()
+                .map(Value::from)
+                .or_else(|_| text.parse::<u64>().map(Value::from))
+                .or_else(|_| text.parse::<f64>().map(Value::from))
+                .unwrap_or(Value::Null)
+        },
+        TokenKind::String => Value::String(tape.string_value(src, tok).into_owned()),
+        TokenKind::ArrayStart => Value::Array(
+            tape.array_elements(idx)
+                .into_iter()
+                .map(|i| value_from_tape(tape, src, i))
+                .collect(),
+        ),
+        TokenKind::ObjectStart => {
+            let mut map = serde_json::Map::new();
+            for (key_idx, value_idx) in tape.object_entries(idx) {
+                let key = tape.string_value(src, tape.token(key_idx)).into_owned();
+                map.insert(key, value_from_tape(tape, src, value_idx));
+            }
+            Value::Object(map)
+        },
+        TokenKind::ObjectEnd | TokenKind::ArrayEnd => Value::Null,
+    }
+}
@@ -0,0 +1,99 @@
+use std::io::Read;
+
+use polars_error::{polars_err, PolarsResult};
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// Compression applied to a [`JsonReader`](super::JsonReader)'s input stream.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default)]
+pub enum Compression {
+    /// Sniff the first bytes of the input and pick the matching decoder, falling
+    /// back to uncompressed if neither magic number is found.
+    #[default]
+    Auto,
+    Gzip,
+    Zstd,
+    Uncompressed,
+}
+
+/// Peek the magic bytes at the front of `reader` and decide which compression, if
+/// any, the stream is using.
+fn sniff<R: Read>(reader: &mut R) -> PolarsResult<(Compression, Vec<u8>)> {
+    let mut prefix = [0u8; 4];
+    let mut filled = 0;
+    while filled < prefix.len() {
+        match reader.read(&mut prefix[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) => return Err(polars_err!(ComputeError: "could not read JSON input: {e}")),
+        }
+    }
+    let prefix = prefix[..filled].to_vec();
+    let kind = if prefix.len() >= 2 && prefix[..2] == GZIP_MAGIC {
+        Compression::Gzip
+    } else if prefix.len() >= 4 && prefix[..4] == ZSTD_MAGIC {
+        Compression::Zstd
+    } else {
+        Compression::Uncompressed
+    };
+    Ok((kind, prefix))
+}
+
+/// Chain `prefix` back in front of `reader` so the decoder sees the bytes that were
+/// consumed while sniffing.
+struct Prefixed<R> {
+    prefix: std::io::Cursor<Vec<u8>>,
+    reader: R,
+}
+
+impl<R: Read> Read for Prefixed<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.prefix.read(buf)?;
+        if n > 0 {
+            return Ok(n);
+        }
+        self.reader.read(buf)
+    }
+}
+
+/// Wrap `reader` in the streaming decoder implied by `compression`, sniffing the
+/// magic bytes first when `compression` is [`Compression::Auto`]. The returned reader
+/// yields decompressed bytes, so it composes transparently with both the batched
+/// `JsonLines` path and the whole-document path, and with schema inference (which
+/// simply reads from the returned reader).
+pub fn decompress<R: Read + 'static>(
+    reader: R,
+    compression: Compression,
+) -> PolarsResult<Box<dyn Read>> {
+    let (detected, prefix) = match compression {
+        Compression::Auto => {
+            let mut reader = reader;
+            let (kind, prefix) = sniff(&mut reader)?;
+            return wrap(Prefixed {
+                prefix: std::io::Cursor::new(prefix),
+                reader,
+            }, kind);
+        },
+        other => (other, Vec::new()),
+    };
+    wrap(
+        Prefixed {
+            prefix: std::io::Cursor::new(prefix),
+            reader,
+        },
+        detected,
+    )
+}
+
+fn wrap<R: Read + 'static>(reader: R, compression: Compression) -> PolarsResult<Box<dyn Read>> {
+    match compression {
+        Compression::Gzip => Ok(Box::new(flate2::read::MultiGzDecoder::new(reader))),
+        Compression::Zstd => Ok(Box::new(
+            zstd::stream::read::Decoder::new(reader)
+                .map_err(|e| polars_err!(ComputeError: "invalid zstd stream: {e}"))?,
+        )),
+        Compression::Uncompressed => Ok(Box::new(reader)),
+        Compression::Auto => unreachable!("Auto is resolved before reaching `wrap`"),
+    }
+}
@@ -0,0 +1,215 @@
+use polars_core::prelude::*;
+use polars_error::{polars_err, PolarsResult};
+use serde_json::Value;
+
+/// The fixed widening order for numbers seen during inference: try the narrowest
+/// integer type first, then a wider integer, and only fall back to `Float64` once a
+/// fractional/exponent form (or an overflow of both integer widths) is observed. This
+/// keeps inference order-independent — the same set of observed categories always
+/// resolves to the same dtype, regardless of which record happens to be scanned first.
+const INT_TYPE_PRIORITY: [DataType; 2] = [DataType::Int64, DataType::UInt64];
+
+/// Coalesce two datatypes observed for the same field/element into a single
+/// datatype that can represent both, widening as needed (`Int64 + Float64 -> Float64`,
+/// anything `+ Utf8 -> Utf8`). `Null` is absorbed into the other side so that a field
+/// seen as both `null` and `T` simply infers as `T` (nullability is handled by the
+/// column's validity bitmap, not the dtype itself).
+///
+/// `Int64` and `UInt64` do not coalesce to either: an `Int64` reading can be negative
+/// (unrepresentable in `UInt64`) and a `UInt64` reading can exceed `i64::MAX`
+/// (unrepresentable in `Int64`), so neither integer type in [`INT_TYPE_PRIORITY`]
+/// is guaranteed to cover both observations — only `Float64` does.
+pub(crate) fn coalesce_dtype(a: DataType, b: DataType) -> DataType {
+    use DataType::*;
+    match (a, b) {
+        (Null, other) | (other, Null) => other,
+        (a, b) if a == b => a,
+        (Int64, UInt64) | (UInt64, Int64) => Float64,
+        (Float64, Int64 | UInt64) | (Int64 | UInt64, Float64) => Float64,
+        (List(a), List(b)) => List(Box::new(coalesce_dtype(*a, *b))),
+        (Struct(a_fields), Struct(b_fields)) => Struct(merge_struct_fields(a_fields, b_fields)),
+        // Anything mixed with a string (or with an otherwise incompatible type)
+        // widens to a string; this is the reader's fallback for heterogeneous data.
+        _ => Utf8,
+    }
+}
+
+/// Pick the narrowest dtype in [`INT_TYPE_PRIORITY`] that covers every category
+/// observed for a numeric field, falling back to `Float64` if none do.
+fn resolve_number_dtype(is_int: bool, is_u64_only: bool, is_fractional: bool) -> DataType {
+    if is_fractional {
+        return DataType::Float64;
+    }
+    debug_assert!(is_int || is_u64_only);
+    for candidate in INT_TYPE_PRIORITY {
+        let covers = match candidate {
+            DataType::Int64 => !is_u64_only,
+            DataType::UInt64 => true,
+            _ => unreachable!(),
+        };
+        if covers {
+            return candidate;
+        }
+    }
+    DataType::Float64
+}
+
+fn merge_struct_fields(a: Vec<Field>, b: Vec<Field>) -> Vec<Field> {
+    let mut out: Vec<Field> = a;
+    for bf in b {
+        if let Some(existing) = out.iter_mut().find(|f| f.name() == bf.name()) {
+            let merged = coalesce_dtype(existing.dtype().clone(), bf.dtype().clone());
+            *existing = Field::new(existing.name(), merged);
+        } else {
+            out.push(bf);
+        }
+    }
+    out
+}
+
+/// Infer the [`DataType`] of a single JSON value, recursing into arrays (-> `List`)
+/// and objects (-> `Struct`).
+pub(crate) fn dtype_of_value(value: &Value) -> DataType {
+    match value {
+        Value::Null => DataType::Null,
+        Value::Bool(_) => DataType::Boolean,
+        Value::Number(n) => resolve_number_dtype(n.is_i64(), !n.is_i64() && n.is_u64(), n.is_f64() && !n.is_i64() && !n.is_u64()),
+        Value::String(_) => DataType::Utf8,
+        Value::Array(values) => {
+            let inner = values
+                .iter()
+                .map(dtype_of_value)
+                .fold(DataType::Null, coalesce_dtype);
+            DataType::List(Box::new(inner))
+        },
+        Value::Object(map) => {
+            let fields = map
+                .iter()
+                .map(|(k, v)| Field::new(k, dtype_of_value(v)))
+                .collect();
+            DataType::Struct(fields)
+        },
+    }
+}
+
+fn merge_field_dtype(schema: &mut Vec<Field>, name: &str, dtype: DataType) {
+    if let Some(field) = schema.iter_mut().find(|f| f.name() == name) {
+        let merged = coalesce_dtype(field.dtype().clone(), dtype);
+        *field = Field::new(field.name(), merged);
+    } else {
+        schema.push(Field::new(name, dtype));
+    }
+}
+
+/// Infer a [`Schema`] by coalescing the datatype of every field across the first
+/// `infer_schema_len` records (or all of them, if `None`). Arrays become `List<inner>`
+/// columns and objects recurse into nested `Struct` fields.
+pub fn infer_schema<'a, I>(values: I, infer_schema_len: Option<usize>) -> Schema
+where
+    I: Iterator<Item = &'a Value>,
+{
+    let mut fields: Vec<Field> = Vec::new();
+    let iter: Box<dyn Iterator<Item = &Value>> = match infer_schema_len {
+        Some(n) => Box::new(values.take(n)),
+        None => Box::new(values),
+    };
+    for value in iter {
+        if let Value::Object(map) = value {
+            for (name, v) in map {
+                merge_field_dtype(&mut fields, name, dtype_of_value(v));
+            }
+        }
+    }
+    Schema::from_iter(fields)
+}
+
+/// Convert one scalar JSON value to an [`AnyValue`] of `dtype`. A missing value,
+/// `null`, or a value of a JSON kind that does not match `dtype` becomes null, except
+/// that any value read as `Utf8` keeps its JSON text. `List`/`Struct` are not handled
+/// here; [`series_from_dtype`] builds those.
+fn any_value_of<'a>(value: Option<&'a Value>, dtype: &DataType) -> AnyValue<'a> {
+    let value = match value {
+        None | Some(Value::Null) => return AnyValue::Null,
+        Some(v) => v,
+    };
+    match (dtype, value) {
+        (DataType::Boolean, Value::Bool(b)) => AnyValue::Boolean(*b),
+        (DataType::Int64, Value::Number(n)) => AnyValue::Int64(n.as_i64().unwrap_or_default()),
+        (DataType::UInt64, Value::Number(n)) => AnyValue::UInt64(n.as_u64().unwrap_or_default()),
+        (DataType::Float64, Value::Number(n)) => {
+            AnyValue::Float64(n.as_f64().unwrap_or_default())
+        },
+        (DataType::Utf8, Value::String(s)) => AnyValue::Utf8Owned(s.as_str().into()),
+        (DataType::Utf8, other) => AnyValue::Utf8Owned(other.to_string().into()),
+        _ => AnyValue::Null,
+    }
+}
+
+/// Materialize a [`DataFrame`] from `values` using the already-inferred `schema`,
+/// recursing into nested `List`/`Struct` series for nested fields.
+pub fn builders_from_values(schema: &Schema, values: &[Value]) -> PolarsResult<DataFrame> {
+    let columns = schema
+        .iter_fields()
+        .map(|field| series_for_field(&field, values))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    DataFrame::new(columns)
+}
+
+fn series_for_field(field: &Field, values: &[Value]) -> PolarsResult<Series> {
+    let field_values: Vec<Option<&Value>> = values
+        .iter()
+        .map(|v| v.as_object().and_then(|m| m.get(field.name().as_str())))
+        .collect();
+    series_from_dtype(field.name(), field.dtype(), &field_values)
+}
+
+pub(crate) fn series_from_dtype(
+    name: &str,
+    dtype: &DataType,
+    values: &[Option<&Value>],
+) -> PolarsResult<Series> {
+    match dtype {
+        DataType::Struct(inner_fields) => {
+            let inner_series = inner_fields
+                .iter()
+                .map(|f| {
+                    let sub_values: Vec<Option<&Value>> = values
+                        .iter()
+                        .map(|v| {
+                            v.and_then(|v| v.as_object())
+                                .and_then(|m| m.get(f.name().as_str()))
+                        })
+                        .collect();
+                    series_from_dtype(f.name(), f.dtype(), &sub_values)
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            StructChunked::new(name, &inner_series).map(|ca| ca.into_series())
+        },
+        DataType::List(inner) => {
+            let mut builder =
+                polars_core::chunked_array::builder::get_list_builder(inner, values.len(), values.len(), name)?;
+            for v in values {
+                match v {
+                    Some(Value::Array(arr)) => {
+                        let elem_values: Vec<Option<&Value>> = arr.iter().map(Some).collect();
+                        let s = series_from_dtype(name, inner, &elem_values)?;
+                        builder.append_series(&s)?;
+                    },
+                    _ => builder.append_null(),
+                }
+            }
+            Ok(builder.finish().into_series())
+        },
+        DataType::Boolean
+        | DataType::Int64
+        | DataType::UInt64
+        | DataType::Float64
+        | DataType::Utf8
+        | DataType::Null => {
+            let any_values: Vec<AnyValue> =
+                values.iter().map(|v| any_value_of(*v, dtype)).collect();
+            Ok(Series::from_any_values_and_dtype(name, &any_values, dtype, true)?)
+        },
+        other => Err(polars_err!(ComputeError: "unsupported dtype {other:?} for JSON field {name:?}")),
+    }
+}
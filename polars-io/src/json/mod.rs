@@ -0,0 +1,330 @@
+mod batch;
+mod decompress;
+mod infer;
+mod path;
+
+use std::io::Read;
+
+use polars_core::prelude::*;
+use polars_error::PolarsResult;
+use serde_json::Value;
+
+pub use self::decompress::Compression;
+pub use self::infer::infer_schema;
+pub use self::path::JsonPath;
+
+/// The on-disk shape of the JSON input.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum JsonFormat {
+    /// One JSON value per line (newline-delimited JSON).
+    JsonLines,
+    /// A single JSON array of records.
+    Json,
+}
+
+/// Read JSON/NDJSON data into a [`DataFrame`].
+///
+/// The first `infer_schema_len` records are scanned to determine a schema, after
+/// which every field (including nested arrays and objects) is materialized into a
+/// typed column.
+pub struct JsonReader<R>
+where
+    R: Read + 'static,
+{
+    reader: R,
+    rechunk: bool,
+    infer_schema_len: Option<usize>,
+    batch_size: usize,
+    json_format: JsonFormat,
+    compression: Compression,
+    schema: Option<Schema>,
+    columns: Option<Vec<String>>,
+    paths: Option<Vec<(String, String)>>,
+}
+
+impl<R> JsonReader<R>
+where
+    R: Read + 'static,
+{
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            rechunk: true,
+            infer_schema_len: Some(100),
+            batch_size: 1024,
+            json_format: JsonFormat::Json,
+            compression: Compression::Uncompressed,
+            schema: None,
+            columns: None,
+            paths: None,
+        }
+    }
+
+    /// Flatten nested input into columns by evaluating a JSONPath expression per
+    /// output column, e.g. `[("name", "$.user.name"), ("first_tag", "$.tags[0].id")]`.
+    /// Supports root `$`, dot/bracket child access, array index `[n]`, and wildcard
+    /// `[*]` (which produces a `List` column). Each column's dtype is inferred across
+    /// the first `infer_schema_len` records exactly like a top-level field. When set,
+    /// this replaces normal field inference/projection entirely.
+    pub fn with_paths(mut self, paths: Vec<(String, String)>) -> Self {
+        self.paths = Some(paths);
+        self
+    }
+
+    /// Bypass schema inference and parse straight into `schema`. Fields declared in
+    /// `schema` but absent from a given record become null; keys present in a record
+    /// but absent from `schema` are ignored.
+    pub fn with_schema(mut self, schema: Schema) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
+    /// Restrict parsing to `columns`. Non-selected keys are skipped rather than
+    /// materialized into a column, which speeds up reads of wide records when only a
+    /// few fields are needed.
+    pub fn with_columns(mut self, columns: Vec<String>) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// Set the number of rows used to infer the schema. `None` scans every record.
+    pub fn infer_schema_len(mut self, infer_schema_len: Option<usize>) -> Self {
+        self.infer_schema_len = infer_schema_len;
+        self
+    }
+
+    pub fn with_json_format(mut self, format: JsonFormat) -> Self {
+        self.json_format = format;
+        self
+    }
+
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    pub fn with_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    /// Transparently decompress the input before parsing. `Compression::Auto` sniffs
+    /// the leading bytes for a gzip or zstd magic number; anything else is passed
+    /// through unchanged.
+    pub fn with_compression(mut self, compression: Option<Compression>) -> Self {
+        self.compression = compression.unwrap_or(Compression::Uncompressed);
+        self
+    }
+
+    fn values(self) -> PolarsResult<Vec<Value>> {
+        let mut reader = decompress::decompress(self.reader, self.compression)?;
+        let mut buf = String::new();
+        reader.read_to_string(&mut buf).map_err(|e| {
+            polars_error::polars_err!(ComputeError: "could not read JSON input: {e}")
+        })?;
+
+        let values = match self.json_format {
+            JsonFormat::Json => {
+                let v: Value = serde_json::from_str(&buf).map_err(|e| {
+                    polars_error::polars_err!(ComputeError: "invalid JSON: {e}")
+                })?;
+                match v {
+                    Value::Array(arr) => arr,
+                    other => vec![other],
+                }
+            },
+            JsonFormat::JsonLines => buf
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(|line| {
+                    serde_json::from_str(line).map_err(|e| {
+                        polars_error::polars_err!(ComputeError: "invalid JSON line: {e}")
+                    })
+                })
+                .collect::<PolarsResult<Vec<_>>>()?,
+        };
+        Ok(values)
+    }
+
+    pub fn finish(mut self) -> PolarsResult<DataFrame> {
+        if let Some(paths) = self.paths.take() {
+            return self.finish_with_paths(paths);
+        }
+        if self.json_format == JsonFormat::JsonLines {
+            return self.finish_jsonlines();
+        }
+
+        let infer_schema_len = self.infer_schema_len;
+        let rechunk = self.rechunk;
+        let columns = self.columns.clone();
+        let schema_override = self.schema.clone();
+        let mut values = self.values()?;
+
+        // Drop non-selected keys up front so inference and column building never see
+        // (or allocate a builder for) a field the caller didn't ask for.
+        if let Some(columns) = &columns {
+            for value in values.iter_mut() {
+                if let Value::Object(map) = value {
+                    map.retain(|k, _| columns.iter().any(|c| c == k));
+                }
+            }
+        }
+
+        let schema = match schema_override {
+            Some(schema) => project_schema(schema, columns.as_deref()),
+            None => infer_schema(values.iter(), infer_schema_len),
+        };
+        let mut df = infer::builders_from_values(&schema, &values)?;
+        if rechunk {
+            df.rechunk();
+        }
+        Ok(df)
+    }
+
+    /// The `JsonFormat::JsonLines` path: parse each batch of lines through a reusable
+    /// tape instead of building an intermediate `serde_json::Value` tree per record.
+    /// Schema inference still samples `Value`s (cheap relative to the full read), but
+    /// the bulk materialization walks tokens directly.
+    fn finish_jsonlines(self) -> PolarsResult<DataFrame> {
+        let infer_schema_len = self.infer_schema_len;
+        let rechunk = self.rechunk;
+        let batch_size = self.batch_size;
+        let columns = self.columns.clone();
+        let schema_override = self.schema.clone();
+        let compression = self.compression;
+
+        let mut reader = decompress::decompress(self.reader, compression)?;
+        let mut buf = String::new();
+        reader.read_to_string(&mut buf).map_err(|e| {
+            polars_error::polars_err!(ComputeError: "could not read JSON input: {e}")
+        })?;
+        let lines: Vec<&str> = buf
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        let schema = match schema_override {
+            Some(schema) => project_schema(schema, columns.as_deref()),
+            None => {
+                let sample_len = infer_schema_len.unwrap_or(lines.len()).min(lines.len());
+                let sample = lines[..sample_len]
+                    .iter()
+                    .map(|line| {
+                        serde_json::from_str(line).map_err(|e| {
+                            polars_error::polars_err!(ComputeError: "invalid JSON line: {e}")
+                        })
+                    })
+                    .collect::<PolarsResult<Vec<Value>>>()?;
+                project_schema(infer_schema(sample.iter(), None), columns.as_deref())
+            },
+        };
+
+        let mut df = batch::read_lines_batched(&lines, &schema, batch_size)?;
+        if rechunk {
+            df.rechunk();
+        }
+        Ok(df)
+    }
+
+    fn finish_with_paths(self, paths: Vec<(String, String)>) -> PolarsResult<DataFrame> {
+        let infer_schema_len = self.infer_schema_len;
+        let rechunk = self.rechunk;
+        let records = self.values()?;
+
+        let compiled = paths
+            .into_iter()
+            .map(|(name, expr)| JsonPath::parse(&expr).map(|path| (name, path)))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let columns = compiled
+            .into_iter()
+            .map(|(name, path)| {
+                let resolved: Vec<Value> = records.iter().map(|r| path.resolve(r)).collect();
+                let inference_window = match infer_schema_len {
+                    Some(n) => &resolved[..n.min(resolved.len())],
+                    None => &resolved[..],
+                };
+                let dtype = inference_window
+                    .iter()
+                    .map(infer::dtype_of_value)
+                    .fold(DataType::Null, infer::coalesce_dtype);
+                let as_refs: Vec<Option<&Value>> = resolved.iter().map(Some).collect();
+                infer::series_from_dtype(&name, &dtype, &as_refs)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut df = DataFrame::new(columns)?;
+        if rechunk {
+            df.rechunk();
+        }
+        Ok(df)
+    }
+}
+
+/// Restrict `schema` to `columns`, preserving the order columns were declared in the
+/// schema (not the order they were requested in).
+fn project_schema(schema: Schema, columns: Option<&[String]>) -> Schema {
+    match columns {
+        None => schema,
+        Some(columns) => Schema::from_iter(
+            schema
+                .iter_fields()
+                .filter(|f| columns.iter().any(|c| c == f.name().as_str())),
+        ),
+    }
+}
+
+/// Convenience wrapper around [`JsonReader`] fixed to the newline-delimited format.
+pub struct JsonLineReader<R>
+where
+    R: Read + 'static,
+{
+    inner: JsonReader<R>,
+}
+
+impl<R> JsonLineReader<R>
+where
+    R: Read + 'static,
+{
+    pub fn new(reader: R) -> Self {
+        Self {
+            inner: JsonReader::new(reader).with_json_format(JsonFormat::JsonLines),
+        }
+    }
+
+    pub fn infer_schema_len(mut self, infer_schema_len: Option<usize>) -> Self {
+        self.inner = self.inner.infer_schema_len(infer_schema_len);
+        self
+    }
+
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.inner = self.inner.with_batch_size(batch_size);
+        self
+    }
+
+    pub fn with_compression(mut self, compression: Option<Compression>) -> Self {
+        self.inner = self.inner.with_compression(compression);
+        self
+    }
+
+    pub fn with_schema(mut self, schema: Schema) -> Self {
+        self.inner = self.inner.with_schema(schema);
+        self
+    }
+
+    pub fn with_columns(mut self, columns: Vec<String>) -> Self {
+        self.inner = self.inner.with_columns(columns);
+        self
+    }
+
+    pub fn with_paths(mut self, paths: Vec<(String, String)>) -> Self {
+        self.inner = self.inner.with_paths(paths);
+        self
+    }
+
+    pub fn finish(self) -> PolarsResult<DataFrame> {
+        self.inner.finish()
+    }
+}
@@ -0,0 +1,83 @@
+use polars_error::{polars_err, PolarsResult};
+use serde_json::Value;
+
+#[derive(Clone, Debug)]
+enum Segment {
+    Child(String),
+    Index(usize),
+    Wildcard,
+}
+
+/// A parsed JSONPath expression, supporting the subset this reader implements: root
+/// `$`, dot child access (`.name`), bracketed child access (`['name']`), array index
+/// (`[n]`), and wildcard (`[*]`).
+#[derive(Clone, Debug)]
+pub struct JsonPath {
+    segments: Vec<Segment>,
+}
+
+impl JsonPath {
+    pub fn parse(path: &str) -> PolarsResult<Self> {
+        let mut chars = path.chars().peekable();
+        if chars.next() != Some('$') {
+            return Err(polars_err!(ComputeError: "JSONPath must start with '$': {path}"));
+        }
+        let mut segments = Vec::new();
+        while let Some(&c) = chars.peek() {
+            match c {
+                '.' => {
+                    chars.next();
+                    let name: String =
+                        std::iter::from_fn(|| chars.next_if(|c| *c != '.' && *c != '[')).collect();
+                    if name.is_empty() {
+                        return Err(polars_err!(ComputeError: "empty path segment in {path}"));
+                    }
+                    segments.push(Segment::Child(name));
+                },
+                '[' => {
+                    chars.next();
+                    let token: String = std::iter::from_fn(|| chars.next_if(|c| *c != ']')).collect();
+                    if chars.next() != Some(']') {
+                        return Err(polars_err!(ComputeError: "unterminated '[' in {path}"));
+                    }
+                    if token == "*" {
+                        segments.push(Segment::Wildcard);
+                    } else if let Ok(idx) = token.parse::<usize>() {
+                        segments.push(Segment::Index(idx));
+                    } else {
+                        let name = token.trim_matches(['\'', '"']).to_string();
+                        segments.push(Segment::Child(name));
+                    }
+                },
+                other => {
+                    return Err(polars_err!(ComputeError: "unexpected character '{other}' in {path}"))
+                },
+            }
+        }
+        Ok(Self { segments })
+    }
+
+    /// Evaluate the path against `value`, returning `Value::Null` where the path is
+    /// absent and fanning a wildcard segment out into a `Value::Array`.
+    pub fn resolve(&self, value: &Value) -> Value {
+        resolve_segments(&self.segments, value)
+    }
+}
+
+fn resolve_segments(segments: &[Segment], value: &Value) -> Value {
+    match segments.split_first() {
+        None => value.clone(),
+        Some((Segment::Child(name), rest)) => match value.get(name) {
+            Some(v) => resolve_segments(rest, v),
+            None => Value::Null,
+        },
+        Some((Segment::Index(idx), rest)) => match value.as_array().and_then(|a| a.get(*idx)) {
+            Some(v) => resolve_segments(rest, v),
+            None => Value::Null,
+        },
+        Some((Segment::Wildcard, rest)) => match value.as_array() {
+            Some(arr) => Value::Array(arr.iter().map(|v| resolve_segments(rest, v)).collect()),
+            None => Value::Array(Vec::new()),
+        },
+    }
+}
@@ -0,0 +1,359 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use polars_error::{polars_err, PolarsResult};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenKind {
+    Null,
+    True,
+    False,
+    Number,
+    String,
+    ObjectStart,
+    ObjectEnd,
+    ArrayStart,
+    ArrayEnd,
+}
+
+/// One entry of a [`Tape`]. Scalars (`Number`/`String`) store a byte span into the
+/// source buffer; `ObjectStart`/`ArrayStart` store the tape index of their matching
+/// `*End` token in `end`, so a consumer that doesn't care about a nested value can
+/// skip straight past it without walking its contents.
+#[derive(Clone, Copy, Debug)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub start: u32,
+    pub end: u32,
+}
+
+/// A flat buffer of [`Token`]s produced by parsing one JSON record at a time. Strings
+/// stay as byte offsets into the source buffer until [`Tape::string_value`]
+/// materializes (and lazily unescapes) them, and compound values are skipped via their
+/// stored end index rather than walked, so looking a field up by key touches only the
+/// tokens between its siblings. Reused across records: call [`Tape::clear`] between
+/// unrelated lines to avoid per-line heap growth.
+#[derive(Default)]
+pub struct Tape {
+    tokens: Vec<Token>,
+}
+
+impl Tape {
+    pub fn clear(&mut self) {
+        self.tokens.clear();
+    }
+
+    pub fn token(&self, idx: usize) -> Token {
+        self.tokens[idx]
+    }
+
+    /// Parse one JSON value out of `src` (typically a single NDJSON line, already
+    /// trimmed of surrounding whitespace) and append its tokens to the tape,
+    /// returning the index of the value's root token.
+    pub fn parse(&mut self, src: &[u8]) -> PolarsResult<usize> {
+        let mut pos = 0;
+        let root = self.parse_value(src, &mut pos)?;
+        skip_ws(src, &mut pos);
+        Ok(root)
+    }
+
+    fn parse_value(&mut self, src: &[u8], pos: &mut usize) -> PolarsResult<usize> {
+        skip_ws(src, pos);
+        match src.get(*pos) {
+            Some(b'{') => self.parse_object(src, pos),
+            Some(b'[') => self.parse_array(src, pos),
+            Some(b'"') => self.parse_string(src, pos),
+            Some(b't') => self.parse_literal(src, pos, b"true", TokenKind::True),
+            Some(b'f') => self.parse_literal(src, pos, b"false", TokenKind::False),
+            Some(b'n') => self.parse_literal(src, pos, b"null", TokenKind::Null),
+            Some(_) => self.parse_number(src, pos),
+            None => Err(polars_err!(ComputeError: "unexpected end of JSON value")),
+        }
+    }
+
+    fn parse_object(&mut self, src: &[u8], pos: &mut usize) -> PolarsResult<usize> {
+        let idx = self.tokens.len();
+        self.tokens.push(Token {
+            kind: TokenKind::ObjectStart,
+            start: 0,
+            end: 0,
+        });
+        *pos += 1; // '{'
+        skip_ws(src, pos);
+        if src.get(*pos) == Some(&b'}') {
+            *pos += 1;
+        } else {
+            loop {
+                skip_ws(src, pos);
+                self.parse_string(src, pos)?;
+                skip_ws(src, pos);
+                if src.get(*pos) != Some(&b':') {
+                    return Err(polars_err!(ComputeError: "expected ':' in JSON object"));
+                }
+                *pos += 1;
+                self.parse_value(src, pos)?;
+                skip_ws(src, pos);
+                match src.get(*pos) {
+                    Some(b',') => {
+                        *pos += 1;
+                        continue;
+                    },
+                    Some(b'}') => {
+                        *pos += 1;
+                        break;
+                    },
+                    _ => return Err(polars_err!(ComputeError: "expected ',' or '}' in JSON object")),
+                }
+            }
+        }
+        let end = self.tokens.len();
+        self.tokens.push(Token {
+            kind: TokenKind::ObjectEnd,
+            start: 0,
+            end: 0,
+        });
+        self.tokens[idx].end = end as u32;
+        Ok(idx)
+    }
+
+    fn parse_array(&mut self, src: &[u8], pos: &mut usize) -> PolarsResult<usize> {
+        let idx = self.tokens.len();
+        self.tokens.push(Token {
+            kind: TokenKind::ArrayStart,
+            start: 0,
+            end: 0,
+        });
+        *pos += 1; // '['
+        skip_ws(src, pos);
+        if src.get(*pos) == Some(&b']') {
+            *pos += 1;
+        } else {
+            loop {
+                self.parse_value(src, pos)?;
+                skip_ws(src, pos);
+                match src.get(*pos) {
+                    Some(b',') => {
+                        *pos += 1;
+                        continue;
+                    },
+                    Some(b']') => {
+                        *pos += 1;
+                        break;
+                    },
+                    _ => return Err(polars_err!(ComputeError: "expected ',' or ']' in JSON array")),
+                }
+            }
+        }
+        let end = self.tokens.len();
+        self.tokens.push(Token {
+            kind: TokenKind::ArrayEnd,
+            start: 0,
+            end: 0,
+        });
+        self.tokens[idx].end = end as u32;
+        Ok(idx)
+    }
+
+    fn parse_string(&mut self, src: &[u8], pos: &mut usize) -> PolarsResult<usize> {
+        if src.get(*pos) != Some(&b'"') {
+            return Err(polars_err!(ComputeError: "expected '\"' to start a JSON string"));
+        }
+        *pos += 1;
+        let start = *pos;
+        loop {
+            match src.get(*pos) {
+                Some(b'\\') => *pos += 2,
+                Some(b'"') => break,
+                Some(_) => *pos += 1,
+                None => return Err(polars_err!(ComputeError: "unterminated JSON string")),
+            }
+        }
+        let end = *pos;
+        *pos += 1; // closing quote
+        let idx = self.tokens.len();
+        self.tokens.push(Token {
+            kind: TokenKind::String,
+            start: start as u32,
+            end: end as u32,
+        });
+        Ok(idx)
+    }
+
+    fn parse_number(&mut self, src: &[u8], pos: &mut usize) -> PolarsResult<usize> {
+        let start = *pos;
+        while matches!(
+            src.get(*pos),
+            Some(b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E')
+        ) {
+            *pos += 1;
+        }
+        if *pos == start {
+            return Err(polars_err!(ComputeError: "invalid JSON value"));
+        }
+        let idx = self.tokens.len();
+        self.tokens.push(Token {
+            kind: TokenKind::Number,
+            start: start as u32,
+            end: *pos as u32,
+        });
+        Ok(idx)
+    }
+
+    fn parse_literal(
+        &mut self,
+        src: &[u8],
+        pos: &mut usize,
+        literal: &[u8],
+        kind: TokenKind,
+    ) -> PolarsResult<usize> {
+        if !src[*pos..].starts_with(literal) {
+            return Err(polars_err!(ComputeError: "invalid JSON literal"));
+        }
+        let start = *pos;
+        *pos += literal.len();
+        let idx = self.tokens.len();
+        self.tokens.push(Token {
+            kind,
+            start: start as u32,
+            end: *pos as u32,
+        });
+        Ok(idx)
+    }
+
+    /// Index of the last token of `idx`'s value: `idx` itself for a scalar, or its
+    /// matching end token for an object or array (whose contents are skipped
+    /// entirely). The next value starts at the returned index + 1.
+    fn skip_value(&self, idx: usize) -> usize {
+        match self.tokens[idx].kind {
+            TokenKind::ObjectStart | TokenKind::ArrayStart => self.tokens[idx].end as usize,
+            _ => idx,
+        }
+    }
+
+    /// Build a `key -> value token index` map for the object rooted at `obj_idx` in a
+    /// single pass. A caller that looks up more than one key per record (e.g. one
+    /// lookup per schema field) should build this once per record and reuse it rather
+    /// than scanning the object's keys again for every field. A key repeated within
+    /// the same object resolves to its last occurrence, matching how a JSON object
+    /// with duplicate keys collapses when parsed into a map.
+    pub fn object_index<'a>(&self, src: &'a [u8], obj_idx: usize) -> HashMap<Cow<'a, str>, usize> {
+        self.object_entries(obj_idx)
+            .into_iter()
+            .map(|(key_idx, value_idx)| (self.string_value(src, self.token(key_idx)), value_idx))
+            .collect()
+    }
+
+    /// Every `(key token index, value token index)` pair inside the object rooted at
+    /// `obj_idx`, in source order (duplicate keys appear once per occurrence; the
+    /// last occurrence is the one that should win when materializing).
+    pub fn object_entries(&self, obj_idx: usize) -> Vec<(usize, usize)> {
+        let obj = self.tokens[obj_idx];
+        debug_assert_eq!(obj.kind, TokenKind::ObjectStart);
+        let end = obj.end as usize;
+        let mut out = Vec::new();
+        let mut i = obj_idx + 1;
+        while i < end {
+            let value_idx = i + 1;
+            out.push((i, value_idx));
+            i = self.skip_value(value_idx) + 1;
+        }
+        out
+    }
+
+    /// Every element's value index inside the array rooted at `arr_idx`, in order.
+    pub fn array_elements(&self, arr_idx: usize) -> Vec<usize> {
+        let arr = self.tokens[arr_idx];
+        debug_assert_eq!(arr.kind, TokenKind::ArrayStart);
+        let end = arr.end as usize;
+        let mut out = Vec::new();
+        let mut i = arr_idx + 1;
+        while i < end {
+            out.push(i);
+            i = self.skip_value(i) + 1;
+        }
+        out
+    }
+
+    pub fn string_value<'a>(&self, src: &'a [u8], token: Token) -> Cow<'a, str> {
+        debug_assert_eq!(token.kind, TokenKind::String);
+        let raw = &src[token.start as usize..token.end as usize];
+        if !raw.contains(&b'\\') {
+            return String::from_utf8_lossy(raw);
+        }
+        Cow::Owned(unescape(raw))
+    }
+
+    pub fn bool_value(&self, token: Token) -> bool {
+        token.kind == TokenKind::True
+    }
+
+    pub fn number_text<'a>(&self, src: &'a [u8], token: Token) -> &'a str {
+        std::str::from_utf8(&src[token.start as usize..token.end as usize]).unwrap_or_default()
+    }
+}
+
+fn skip_ws(src: &[u8], pos: &mut usize) {
+    while matches!(src.get(*pos), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+        *pos += 1;
+    }
+}
+
+/// Decode JSON string escapes (`\"`, `\\`, `\/`, `\n`, `\t`, `\r`, `\b`, `\f`, `\uXXXX`,
+/// including a `\uXXXX\uXXXX` UTF-16 surrogate pair for non-BMP characters). Only
+/// called when the raw span actually contains a backslash.
+fn unescape(raw: &[u8]) -> String {
+    let raw = String::from_utf8_lossy(raw);
+    let mut out = String::with_capacity(raw.len());
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('"') => out.push('"'),
+            Some('\\') => out.push('\\'),
+            Some('/') => out.push('/'),
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('r') => out.push('\r'),
+            Some('b') => out.push('\u{8}'),
+            Some('f') => out.push('\u{c}'),
+            Some('u') => out.push(decode_unicode_escape(&mut chars)),
+            Some(other) => out.push(other),
+            None => {},
+        }
+    }
+    out
+}
+
+fn read_hex4(chars: &mut std::str::Chars) -> Option<u32> {
+    let hex: String = chars.take(4).collect();
+    u32::from_str_radix(&hex, 16).ok()
+}
+
+/// Decode a `\uXXXX` escape already past the `u`, combining it with a following
+/// `\uXXXX` low surrogate when the first unit is a UTF-16 high surrogate. An
+/// unpaired or invalid surrogate falls back to the replacement character rather than
+/// silently dropping the character.
+fn decode_unicode_escape(chars: &mut std::str::Chars) -> char {
+    let Some(unit) = read_hex4(chars) else {
+        return char::REPLACEMENT_CHARACTER;
+    };
+    if !(0xD800..=0xDBFF).contains(&unit) {
+        return char::from_u32(unit).unwrap_or(char::REPLACEMENT_CHARACTER);
+    }
+    let mut lookahead = chars.clone();
+    if lookahead.next() != Some('\\') || lookahead.next() != Some('u') {
+        return char::REPLACEMENT_CHARACTER;
+    }
+    let Some(low) = read_hex4(&mut lookahead) else {
+        return char::REPLACEMENT_CHARACTER;
+    };
+    if !(0xDC00..=0xDFFF).contains(&low) {
+        return char::REPLACEMENT_CHARACTER;
+    }
+    *chars = lookahead;
+    let code = 0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00);
+    char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+}
@@ -108,3 +108,305 @@ fn read_unordered_json() {
     assert_eq!("d", df.get_columns()[3].name());
     assert_eq!((12, 4), df.shape());
 }
+
+#[test]
+fn read_json_nested_list_and_struct() {
+    let nested_json = r#"{"a":1, "b":[2.0, 1.3], "c":{"x":false}}
+{"a":2, "b":[3.0], "c":{"x":true}}
+{"a":3, "b":[], "c":{"x":false}}
+"#;
+    let file = Cursor::new(nested_json);
+    let df = JsonReader::new(file)
+        .infer_schema_len(Some(3))
+        .with_json_format(JsonFormat::JsonLines)
+        .finish()
+        .unwrap();
+    assert_eq!(
+        &DataType::List(Box::new(DataType::Float64)),
+        df.column("b").unwrap().dtype()
+    );
+    assert_eq!(
+        &DataType::Struct(vec![Field::new("x", DataType::Boolean)]),
+        df.column("c").unwrap().dtype()
+    );
+    assert_eq!((3, 3), df.shape());
+}
+
+#[test]
+fn read_json_nested_null_becomes_nullable() {
+    let nested_json = r#"{"a":1, "b":null}
+{"a":null, "b":2}
+"#;
+    let file = Cursor::new(nested_json);
+    let df = JsonReader::new(file)
+        .infer_schema_len(Some(2))
+        .with_json_format(JsonFormat::JsonLines)
+        .finish()
+        .unwrap();
+    assert_eq!(&DataType::Int64, df.column("a").unwrap().dtype());
+    assert_eq!(1, df.column("a").unwrap().null_count());
+    assert_eq!(1, df.column("b").unwrap().null_count());
+}
+
+#[test]
+fn read_json_keeps_large_integers_as_int64() {
+    let basic_json = r#"{"a":1}
+{"a":100000000000000}
+"#;
+    let file = Cursor::new(basic_json);
+    let df = JsonReader::new(file)
+        .infer_schema_len(Some(2))
+        .with_json_format(JsonFormat::JsonLines)
+        .finish()
+        .unwrap();
+    assert_eq!(&DataType::Int64, df.column("a").unwrap().dtype());
+}
+
+#[test]
+fn read_json_widens_to_uint64_beyond_i64_max() {
+    let basic_json = r#"{"a":1}
+{"a":18446744073709551615}
+"#;
+    let file = Cursor::new(basic_json);
+    let df = JsonReader::new(file)
+        .infer_schema_len(Some(2))
+        .with_json_format(JsonFormat::JsonLines)
+        .finish()
+        .unwrap();
+    assert_eq!(&DataType::UInt64, df.column("a").unwrap().dtype());
+}
+
+#[test]
+fn read_gzip_compressed_json() {
+    use std::io::Write;
+
+    use flate2::write::GzEncoder;
+    use flate2::Compression as GzCompression;
+
+    let basic_json = r#"{"a":1, "b":2.0}
+{"a":2, "b":3.0}
+"#;
+    let mut encoder = GzEncoder::new(Vec::new(), GzCompression::default());
+    encoder.write_all(basic_json.as_bytes()).unwrap();
+    let compressed = encoder.finish().unwrap();
+
+    let df = JsonReader::new(Cursor::new(compressed))
+        .with_json_format(JsonFormat::JsonLines)
+        .with_compression(Some(Compression::Auto))
+        .finish()
+        .unwrap();
+    assert_eq!((2, 2), df.shape());
+}
+
+#[test]
+fn read_zstd_compressed_json() {
+    let basic_json = r#"{"a":1, "b":2.0}
+{"a":2, "b":3.0}
+"#;
+    let compressed = zstd::stream::encode_all(Cursor::new(basic_json), 0).unwrap();
+
+    let df = JsonReader::new(Cursor::new(compressed))
+        .with_json_format(JsonFormat::JsonLines)
+        .with_compression(Some(Compression::Auto))
+        .finish()
+        .unwrap();
+    assert_eq!((2, 2), df.shape());
+}
+
+#[test]
+fn read_json_with_columns_projection() {
+    let basic_json = r#"{"a":1, "b":2.0, "c":"x"}
+{"a":2, "b":3.0, "c":"y"}
+"#;
+    let file = Cursor::new(basic_json);
+    let df = JsonReader::new(file)
+        .with_json_format(JsonFormat::JsonLines)
+        .with_columns(vec!["a".to_string(), "c".to_string()])
+        .finish()
+        .unwrap();
+    assert_eq!((2, 2), df.shape());
+    assert_eq!(vec!["a", "c"], df.get_column_names());
+}
+
+#[test]
+fn read_json_with_explicit_schema() {
+    let basic_json = r#"{"a":1}
+{"a":2, "b":"unexpected"}
+"#;
+    let schema = Schema::from_iter(vec![Field::new("a", DataType::Int64)]);
+    let file = Cursor::new(basic_json);
+    let df = JsonReader::new(file)
+        .with_json_format(JsonFormat::JsonLines)
+        .with_schema(schema)
+        .finish()
+        .unwrap();
+    assert_eq!(vec!["a"], df.get_column_names());
+    assert_eq!((2, 1), df.shape());
+}
+
+#[test]
+fn read_json_schema_field_missing_from_record_is_null() {
+    let basic_json = r#"{"a":1, "b":2}
+{"a":2}
+"#;
+    let schema = Schema::from_iter(vec![
+        Field::new("a", DataType::Int64),
+        Field::new("b", DataType::Int64),
+    ]);
+    let file = Cursor::new(basic_json);
+    let df = JsonReader::new(file)
+        .with_json_format(JsonFormat::JsonLines)
+        .with_schema(schema)
+        .finish()
+        .unwrap();
+    assert_eq!(1, df.column("b").unwrap().null_count());
+}
+
+#[test]
+fn read_json_with_jsonpath_extraction() {
+    let nested_json = r#"{"user":{"name":"x"}, "tags":[{"id":1},{"id":2}]}
+{"user":{"name":"y"}, "tags":[{"id":3}]}
+"#;
+    let file = Cursor::new(nested_json);
+    let df = JsonReader::new(file)
+        .with_json_format(JsonFormat::JsonLines)
+        .with_paths(vec![
+            ("name".to_string(), "$.user.name".to_string()),
+            ("first_tag".to_string(), "$.tags[0].id".to_string()),
+        ])
+        .finish()
+        .unwrap();
+    assert_eq!(vec!["name", "first_tag"], df.get_column_names());
+    assert_eq!(AnyValue::Utf8("x"), df.column("name").unwrap().get(0));
+    assert_eq!(AnyValue::Int64(1), df.column("first_tag").unwrap().get(0));
+    assert_eq!(AnyValue::Int64(3), df.column("first_tag").unwrap().get(1));
+}
+
+#[test]
+fn read_json_with_jsonpath_wildcard_produces_list() {
+    let nested_json = r#"{"tags":[{"id":1},{"id":2}]}
+{"tags":[{"id":3}]}
+"#;
+    let file = Cursor::new(nested_json);
+    let df = JsonReader::new(file)
+        .with_json_format(JsonFormat::JsonLines)
+        .with_paths(vec![("ids".to_string(), "$.tags[*].id".to_string())])
+        .finish()
+        .unwrap();
+    assert_eq!(
+        &DataType::List(Box::new(DataType::Int64)),
+        df.column("ids").unwrap().dtype()
+    );
+}
+
+#[test]
+fn read_json_with_jsonpath_missing_path_is_null() {
+    let nested_json = r#"{"a":1}
+{"a":2, "user":{"name":"y"}}
+"#;
+    let file = Cursor::new(nested_json);
+    let df = JsonReader::new(file)
+        .with_json_format(JsonFormat::JsonLines)
+        .with_paths(vec![("name".to_string(), "$.user.name".to_string())])
+        .finish()
+        .unwrap();
+    assert_eq!(AnyValue::Null, df.column("name").unwrap().get(0));
+    assert_eq!(AnyValue::Utf8("y"), df.column("name").unwrap().get(1));
+}
+
+#[test]
+fn read_json_lines_tape_path_spans_multiple_batches() {
+    let mut lines = String::new();
+    for i in 0..37 {
+        lines.push_str(&format!("{{\"a\":{i}, \"b\":\"row{i}\"}}\n"));
+    }
+    let file = Cursor::new(lines);
+    let df = JsonReader::new(file)
+        .with_json_format(JsonFormat::JsonLines)
+        .with_batch_size(8)
+        .finish()
+        .unwrap();
+    assert_eq!((37, 2), df.shape());
+    assert_eq!(AnyValue::Int64(0), df.column("a").unwrap().get(0));
+    assert_eq!(AnyValue::Int64(36), df.column("a").unwrap().get(36));
+    assert_eq!(AnyValue::Utf8("row36"), df.column("b").unwrap().get(36));
+}
+
+#[test]
+fn read_json_lines_all_null_field_does_not_panic() {
+    let basic_json = r#"{"a":1, "b":null}
+{"a":2, "b":null}
+"#;
+    let file = Cursor::new(basic_json);
+    let df = JsonReader::new(file)
+        .infer_schema_len(Some(2))
+        .with_json_format(JsonFormat::JsonLines)
+        .finish()
+        .unwrap();
+    assert_eq!(&DataType::Null, df.column("b").unwrap().dtype());
+    assert_eq!(2, df.column("b").unwrap().null_count());
+}
+
+#[test]
+fn read_json_lines_decodes_surrogate_pair_escape() {
+    // "a" is a 😀 UTF-16 surrogate pair escape for U+1F600 (emoji), not the
+    // literal UTF-8 bytes, so this exercises `decode_unicode_escape`'s pairing path.
+    let basic_json = "{\"a\":\"x\\uD83D\\uDE00y\"}\n";
+    let file = Cursor::new(basic_json);
+    let df = JsonReader::new(file)
+        .with_json_format(JsonFormat::JsonLines)
+        .finish()
+        .unwrap();
+    assert_eq!(
+        AnyValue::Utf8("x\u{1F600}y"),
+        df.column("a").unwrap().get(0)
+    );
+}
+
+#[test]
+fn read_json_negative_int_and_u64_overflow_widen_to_float64() {
+    let basic_json = r#"{"a":-1}
+{"a":18446744073709551615}
+"#;
+    let file = Cursor::new(basic_json);
+    let df = JsonReader::new(file)
+        .infer_schema_len(Some(2))
+        .with_json_format(JsonFormat::JsonLines)
+        .finish()
+        .unwrap();
+    assert_eq!(&DataType::Float64, df.column("a").unwrap().dtype());
+}
+
+#[test]
+fn read_json_unsupported_schema_dtype_errors_instead_of_panicking() {
+    let basic_json = r#"{"a":1}
+"#;
+    let schema = Schema::from_iter(vec![Field::new("a", DataType::Int32)]);
+
+    let lines_err = JsonReader::new(Cursor::new(basic_json))
+        .with_json_format(JsonFormat::JsonLines)
+        .with_schema(schema.clone())
+        .finish()
+        .unwrap_err();
+    assert!(lines_err.to_string().contains("unsupported dtype"));
+
+    let doc_err = JsonReader::new(Cursor::new(basic_json))
+        .with_schema(schema)
+        .finish()
+        .unwrap_err();
+    assert!(doc_err.to_string().contains("unsupported dtype"));
+}
+
+#[test]
+fn read_json_lines_wide_record_with_duplicate_and_escaped_keys() {
+    let wide_json = "{\"a\":1, \"b\":2, \"c\":3, \"d\":4, \"\\\"q\\\"\":5, \"a\":9}\n{\"a\":10, \"b\":20, \"c\":30, \"d\":40, \"\\\"q\\\"\":50, \"a\":90}\n";
+    let file = Cursor::new(wide_json);
+    let df = JsonReader::new(file)
+        .with_json_format(JsonFormat::JsonLines)
+        .finish()
+        .unwrap();
+    // duplicate "a" keys resolve to the last occurrence in each record.
+    assert_eq!(AnyValue::Int64(9), df.column("a").unwrap().get(0));
+    assert_eq!(AnyValue::Int64(90), df.column("a").unwrap().get(1));
+    assert_eq!(AnyValue::Int64(5), df.column("\"q\"").unwrap().get(0));
+}